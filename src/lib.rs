@@ -15,38 +15,486 @@
 //! assert_eq!("1.0Yi", iec(1_208_925_819_614_629_174_706_176_u128));
 //! ```
 
+const DECIMAL_MULTS: [&str; 9] = ["", "k", "M", "G", "T", "P", "E", "Z", "Y"];
+const IEC_MULTS: [&str; 9] = ["", "ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"];
+
+// `u128::MAX` is about 3.4e38, so 38 is the largest exponent for which `10^exp` still fits
+// (`10^39` would not), and `1023 * 10^35` (the worst-case `remainder * 10^decimals` in
+// `reduce_magnitude`) stays under that same ceiling with room to spare — hence the two bounds
+// below.
+/// Largest exponent for which `10^exp` fits in a `u128`.
+const MAX_POW10_EXP: u32 = 38;
+
+/// `10^exp`, clamped to [`MAX_POW10_EXP`] so a caller-supplied exponent can't overflow.
+fn pow10_clamped(exp: u32) -> u128 {
+    10u128.pow(exp.min(MAX_POW10_EXP))
+}
+
 /// Format value as decimal multipliers (that is in 1000 increments) with one decimal place.
 pub fn decimal(val: u128) -> String {
-    const MULTS: [&str; 9] = ["", "k", "M", "G", "T", "P", "E", "Z", "Y"];
+    decimal_magnitude(val)
+}
+
+/// Core of [`decimal`]/[`decimal_i128`]: format an unsigned magnitude with no sign.
+fn decimal_magnitude(val: u128) -> String {
+    Buffer::new().format_decimal(val).to_string()
+}
+
+/// Format value as IEC multipliers (that is in 1024 increments) with one decimal place.
+pub fn iec(val: u128) -> String {
+    iec_magnitude(val)
+}
+
+/// Core of [`iec`]/[`iec_i128`]: format an unsigned magnitude with no sign.
+fn iec_magnitude(val: u128) -> String {
+    Buffer::new().format_iec(val).to_string()
+}
+
+/// Rounding applied to the fractional digits dropped by [`decimal_with`]/[`iec_with`] once a
+/// value has been reduced to its chosen unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Drop the extra digits (the behavior of [`decimal`]/[`iec`]).
+    Truncate,
+    /// Round half away from zero.
+    HalfUp,
+    /// Round half to even (banker's rounding) on exact ties.
+    HalfEven,
+}
+
+/// Formatting options for [`decimal_with`]/[`iec_with`]: how many fractional digits to keep and
+/// how to round the remainder. `decimals` above [`MAX_DECIMALS`] is clamped rather than rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Format {
+    pub decimals: u8,
+    pub rounding: Rounding,
+}
+
+impl Default for Format {
+    /// Matches [`decimal`]/[`iec`]: one truncated fractional digit.
+    fn default() -> Self {
+        Format {
+            decimals: 1,
+            rounding: Rounding::Truncate,
+        }
+    }
+}
+
+/// Largest `decimals`/precision [`reduce_magnitude`] will honor; see [`MAX_POW10_EXP`] for why.
+const MAX_DECIMALS: u8 = 35;
+
+/// Clamp a caller-supplied precision to [`MAX_DECIMALS`].
+fn clamp_decimals(decimals: u8) -> u8 {
+    decimals.min(MAX_DECIMALS)
+}
+
+/// Shared core for `decimal`/`iec` and their `_with`/`Buffer` variants: reduce `val` to its unit
+/// using `base`-sized steps through `mults`, then split the remainder into `decimals`
+/// fractional digits applying `rounding`. Returns `(integer_part, fractional_digits, suffix)`.
+fn reduce_magnitude<'a>(
+    val: u128,
+    base: u128,
+    mults: &[&'a str],
+    decimals: u8,
+    rounding: Rounding,
+) -> (u128, u128, &'a str) {
+    let decimals = clamp_decimals(decimals);
     let mut s = 0;
     let mut v: u128 = val;
-    let mut t: u128 = 0;
+    let mut r: u128 = 0;
 
-    while v >= 1000 && s < MULTS.len() {
+    while v >= base && s < mults.len() - 1 {
         s += 1;
-        t = (v % 1000) / 100;
-        v /= 1000;
+        r = v % base;
+        v /= base;
+    }
+
+    let pow10 = pow10_clamped(decimals as u32);
+    let scaled = r * pow10;
+    let mut frac = scaled / base;
+    let rem = scaled % base;
+
+    // The digit a tie rounds against: the last fractional digit, or (when no fractional
+    // digits are kept) the integer part itself.
+    let last_digit_odd = if decimals == 0 { v % 2 == 1 } else { frac % 2 == 1 };
+
+    let round_up = match rounding {
+        Rounding::Truncate => false,
+        Rounding::HalfUp => rem * 2 >= base,
+        Rounding::HalfEven => rem * 2 > base || (rem * 2 == base && last_digit_odd),
+    };
+
+    if round_up {
+        frac += 1;
+        if frac >= pow10 {
+            frac = 0;
+            v += 1;
+            if v >= base && s < mults.len() - 1 {
+                s += 1;
+                v /= base;
+            }
+        }
     }
 
-    format!("{}.{}{}", v, t, MULTS[s])
+    (v, frac, mults[s])
 }
 
-/// Format value as IEC multipliers (that is in 1024 increments) with one decimal place.
-pub fn iec(val: u128) -> String {
-    const MULTS: [&str; 9] = ["", "ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"];
+/// Format value as decimal multipliers with the precision and rounding mode given by `format`.
+pub fn decimal_with(val: u128, format: Format) -> String {
+    let decimals = clamp_decimals(format.decimals);
+    let (v, frac, suffix) = reduce_magnitude(val, 1000, &DECIMAL_MULTS, decimals, format.rounding);
+    render_magnitude(v, frac, suffix, decimals)
+}
+
+/// Format value as IEC multipliers with the precision and rounding mode given by `format`.
+pub fn iec_with(val: u128, format: Format) -> String {
+    let decimals = clamp_decimals(format.decimals);
+    let (v, frac, suffix) = reduce_magnitude(val, 1024, &IEC_MULTS, decimals, format.rounding);
+    render_magnitude(v, frac, suffix, decimals)
+}
+
+/// Render a reduced `(integer_part, fractional_digits, suffix)` triple as an owned string.
+fn render_magnitude(v: u128, frac: u128, suffix: &str, decimals: u8) -> String {
+    if decimals == 0 {
+        format!("{}{}", v, suffix)
+    } else {
+        format!("{}.{:0width$}{}", v, frac, suffix, width = decimals as usize)
+    }
+}
+
+/// Reduce a scaled integer (`mantissa / 10^scale`, the mantissa-and-scale representation used
+/// for fixed-point quantities) using the same `base`-sized suffix steps as [`reduce_magnitude`].
+/// The `10^scale` divisor is grown instead of dividing `mantissa` down at each step, so no
+/// precision is lost before the final division. Returns `(integer_part, one_fractional_digit,
+/// suffix)`.
+fn reduce_scaled<'a>(mantissa: u128, scale: u32, base: u128, mults: &[&'a str]) -> (u128, u128, &'a str) {
+    let mut denom = pow10_clamped(scale);
     let mut s = 0;
-    let mut v: u128 = val;
-    let mut t: u128 = 0;
 
-    while v >= 1024 && s < MULTS.len() {
+    while mantissa / denom >= base && s < mults.len() - 1 {
+        denom = match denom.checked_mul(base) {
+            Some(grown) => grown,
+            None => break,
+        };
         s += 1;
+    }
+
+    let v = mantissa / denom;
+    let rem = mantissa % denom;
+    // `rem * 10` can overflow u128 once `denom` (scale and suffix steps combined) gets close to
+    // u128::MAX, e.g. a Decimal128 at its maximum scale of 38. Fall back to dividing `denom`
+    // first in that case; `rem < denom` so `denom / 10` is always a valid divisor.
+    let frac = match rem.checked_mul(10) {
+        Some(scaled) => scaled / denom,
+        None => rem / (denom / 10),
+    };
+
+    (v, frac, mults[s])
+}
+
+/// Format a scaled integer — `mantissa / 10^scale`, the `(mantissa, scale)` representation used
+/// for fixed-point quantities such as `arrow`'s `Decimal128` — as decimal multipliers with one
+/// decimal place.
+///
+/// ```
+/// use pakr_iec::decimal_scaled;
+/// assert_eq!("1.5k", decimal_scaled(1_500_000, 3));
+/// ```
+pub fn decimal_scaled(mantissa: u128, scale: u32) -> String {
+    let (v, frac, suffix) = reduce_scaled(mantissa, scale, 1000, &DECIMAL_MULTS);
+    render_magnitude(v, frac, suffix, 1)
+}
+
+/// Format a scaled integer — `mantissa / 10^scale` — as IEC multipliers with one decimal place.
+pub fn iec_scaled(mantissa: u128, scale: u32) -> String {
+    let (v, frac, suffix) = reduce_scaled(mantissa, scale, 1024, &IEC_MULTS);
+    render_magnitude(v, frac, suffix, 1)
+}
+
+/// A `u128` that [`Display`](std::fmt::Display)s as decimal multipliers, honoring `{:.N}`
+/// precision (defaulting to one fractional digit, like [`decimal`]) and `{:>N}` width/fill so it
+/// can be dropped straight into a larger `format!`/`println!` call. `N` above [`MAX_DECIMALS`] is
+/// clamped rather than panicking.
+///
+/// # Example
+///
+/// ```
+/// use pakr_iec::Decimal;
+/// assert_eq!("1.0k", format!("{}", Decimal(1000)));
+/// assert_eq!("1.500k", format!("{:.3}", Decimal(1500)));
+/// assert_eq!(" 1.0k", format!("{:>5}", Decimal(1000)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decimal(pub u128);
+
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let decimals = f.precision().unwrap_or(1).min(MAX_DECIMALS as usize) as u8;
+        let (v, frac, suffix) = reduce_magnitude(self.0, 1000, &DECIMAL_MULTS, decimals, Rounding::Truncate);
+        pad_output(f, &render_magnitude(v, frac, suffix, decimals))
+    }
+}
+
+/// A `u128` that [`Display`](std::fmt::Display)s as IEC multipliers, honoring `{:.N}` precision
+/// (defaulting to one fractional digit, like [`iec`]) and `{:>N}` width/fill so it can be
+/// dropped straight into a larger `format!`/`println!` call. `N` above [`MAX_DECIMALS`] is
+/// clamped rather than panicking.
+///
+/// # Example
+///
+/// ```
+/// use pakr_iec::Iec;
+/// assert_eq!("1.0ki", format!("{}", Iec(1024)));
+/// assert_eq!("1.000ki", format!("{:.3}", Iec(1024)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Iec(pub u128);
+
+impl std::fmt::Display for Iec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let decimals = f.precision().unwrap_or(1).min(MAX_DECIMALS as usize) as u8;
+        let (v, frac, suffix) = reduce_magnitude(self.0, 1024, &IEC_MULTS, decimals, Rounding::Truncate);
+        pad_output(f, &render_magnitude(v, frac, suffix, decimals))
+    }
+}
+
+/// Write `s` to `f` honoring its width/fill/alignment, without touching `f.precision()` (which
+/// [`Decimal`]/[`Iec`] have already consumed to choose the number of fractional digits, not the
+/// output string length — unlike `Formatter::pad`, which would truncate by precision).
+fn pad_output(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    use std::fmt::Write;
+
+    let width = match f.width() {
+        Some(width) => width,
+        None => return f.write_str(s),
+    };
+
+    let pad = width.saturating_sub(s.chars().count());
+    if pad == 0 {
+        return f.write_str(s);
+    }
+
+    let fill = f.fill();
+    let (left, right) = match f.align() {
+        Some(std::fmt::Alignment::Right) => (pad, 0),
+        Some(std::fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+        _ => (0, pad),
+    };
+
+    for _ in 0..left {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..right {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Longest possible output of [`Buffer::format_decimal`]/[`Buffer::format_iec`]: the widest
+/// `u128` integer part the suffix tables leave unreduced, a dot, one fractional digit, and a
+/// two-character suffix — comfortably under 32 bytes.
+const BUFFER_CAPACITY: usize = 32;
+
+/// A reusable, stack-allocated buffer for allocation-free decimal/IEC formatting.
+///
+/// Call [`Buffer::format_decimal`]/[`Buffer::format_iec`] repeatedly; each call overwrites the
+/// buffer in place and returns a borrowed `&str`, so formatting a value costs no heap
+/// allocation.
+pub struct Buffer {
+    bytes: [u8; BUFFER_CAPACITY],
+    len: usize,
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Buffer::new()
+    }
+}
+
+impl Buffer {
+    /// Create an empty buffer.
+    pub fn new() -> Self {
+        Buffer {
+            bytes: [0; BUFFER_CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Format `val` as decimal multipliers with one decimal place, writing into this buffer.
+    pub fn format_decimal(&mut self, val: u128) -> &str {
+        self.write(val, 1000, &DECIMAL_MULTS)
+    }
+
+    /// Format `val` as IEC multipliers with one decimal place, writing into this buffer.
+    pub fn format_iec(&mut self, val: u128) -> &str {
+        self.write(val, 1024, &IEC_MULTS)
+    }
+
+    fn write(&mut self, val: u128, base: u128, mults: &[&str]) -> &str {
+        let (v, frac, suffix) = reduce_magnitude(val, base, mults, 1, Rounding::Truncate);
+
+        let mut len = write_digits(&mut self.bytes, v);
+        self.bytes[len] = b'.';
+        len += 1;
+        self.bytes[len] = b'0' + frac as u8;
+        len += 1;
+        let suffix = suffix.as_bytes();
+        self.bytes[len..len + suffix.len()].copy_from_slice(suffix);
+        len += suffix.len();
+
+        self.len = len;
+        // SAFETY: every byte written above is ASCII (digits, '.', or the ASCII suffix table).
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+/// Write `v`'s decimal digits (at least one, even for zero) into `buf` and return how many bytes
+/// were written.
+fn write_digits(buf: &mut [u8], mut v: u128) -> usize {
+    if v == 0 {
+        buf[0] = b'0';
+        return 1;
+    }
+
+    let mut digits = [0u8; 40];
+    let mut n = 0;
+    while v > 0 {
+        digits[n] = b'0' + (v % 10) as u8;
+        v /= 10;
+        n += 1;
+    }
+    for i in 0..n {
+        buf[i] = digits[n - 1 - i];
+    }
+    n
+}
+
+/// Format a signed value as decimal multipliers, with a leading `-` for negative input.
+///
+/// Uses `val.unsigned_abs()` to get the magnitude, so `i128::MIN` formats correctly instead of
+/// panicking (its absolute value doesn't fit in `i128`).
+pub fn decimal_i128(val: i128) -> String {
+    if val.is_negative() {
+        format!("-{}", decimal_magnitude(val.unsigned_abs()))
+    } else {
+        decimal_magnitude(val as u128)
+    }
+}
+
+/// Format a signed value as IEC multipliers, with a leading `-` for negative input.
+///
+/// Uses `val.unsigned_abs()` to get the magnitude, so `i128::MIN` formats correctly instead of
+/// panicking (its absolute value doesn't fit in `i128`).
+pub fn iec_i128(val: i128) -> String {
+    if val.is_negative() {
+        format!("-{}", iec_magnitude(val.unsigned_abs()))
+    } else {
+        iec_magnitude(val as u128)
+    }
+}
+
+/// Error returned by [`parse_decimal`] and [`parse_iec`] when a string cannot be parsed back
+/// into a magnitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The numeric part is missing or is not made of digits.
+    InvalidNumber,
+    /// The suffix is not one of the known multipliers.
+    UnknownSuffix,
+    /// The value does not fit in a `u128`. Also returned when the integer or fractional digits
+    /// on their own, or the fractional digits times the suffix multiplier, do not fit, even if
+    /// the value itself would.
+    Overflow,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidNumber => write!(f, "invalid number"),
+            ParseError::UnknownSuffix => write!(f, "unknown suffix"),
+            ParseError::Overflow => write!(f, "value overflows u128"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Shared core for [`parse_decimal`]/[`parse_iec`]: split `s` into a numeric head and an
+/// alphabetic suffix, look the suffix up in `mults` to get an exponent, then recombine the
+/// integer and fractional digits using `base`-scaled `u128` math.
+fn parse_value(s: &str, mults: &[&str], base: u128) -> Result<u128, ParseError> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+    let (num, suffix) = s.split_at(split);
 
-        t = v % 1024;
-        v /= 1024;
+    let exp = if suffix.is_empty() {
+        0
+    } else {
+        mults
+            .iter()
+            .position(|&m| m == suffix)
+            .ok_or(ParseError::UnknownSuffix)?
+    };
+
+    let (int_part, frac_part) = match num.split_once('.') {
+        Some((i, f)) => (i, f),
+        None => (num, ""),
+    };
+
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(ParseError::InvalidNumber);
     }
-    t = 10 * t / 1024;
 
-    format!("{}.{}{}", v, t, MULTS[s])
+    // `int_part`/`frac_part` are substrings of `num`, which the split above guaranteed contains
+    // only ASCII digits and `.` — so as long as a part doesn't itself contain a stray `.` (e.g.
+    // "1.2.3"), every character in it is a digit and a `parse` failure can only mean the digits
+    // don't fit in a `u128`, not that the number is malformed.
+    let int_val: u128 = if int_part.is_empty() {
+        0
+    } else if int_part.bytes().all(|b| b.is_ascii_digit()) {
+        int_part.parse().map_err(|_| ParseError::Overflow)?
+    } else {
+        return Err(ParseError::InvalidNumber);
+    };
+    let frac_val: u128 = if frac_part.is_empty() {
+        0
+    } else if frac_part.bytes().all(|b| b.is_ascii_digit()) {
+        frac_part.parse().map_err(|_| ParseError::Overflow)?
+    } else {
+        return Err(ParseError::InvalidNumber);
+    };
+
+    let mut mult: u128 = 1;
+    for _ in 0..exp {
+        mult = mult.checked_mul(base).ok_or(ParseError::Overflow)?;
+    }
+
+    let whole = int_val.checked_mul(mult).ok_or(ParseError::Overflow)?;
+
+    let frac_scaled = if frac_part.is_empty() {
+        0
+    } else {
+        let denom = 10u128
+            .checked_pow(frac_part.len() as u32)
+            .ok_or(ParseError::Overflow)?;
+        let frac_mult = frac_val.checked_mul(mult).ok_or(ParseError::Overflow)?;
+        frac_mult / denom
+    };
+
+    whole.checked_add(frac_scaled).ok_or(ParseError::Overflow)
+}
+
+/// Parse a decimal-multiplier string (e.g. `"1.5M"`, `"10k"`) produced by [`decimal`] back into
+/// a `u128`.
+pub fn parse_decimal(s: &str) -> Result<u128, ParseError> {
+    parse_value(s, &DECIMAL_MULTS, 1000)
+}
+
+/// Parse an IEC-multiplier string (e.g. `"2.5Gi"`, `"10ki"`) produced by [`iec`] back into a
+/// `u128`.
+pub fn parse_iec(s: &str) -> Result<u128, ParseError> {
+    parse_value(s, &IEC_MULTS, 1024)
 }
 
 #[cfg(test)]
@@ -98,4 +546,230 @@ mod tests {
 
         assert_eq!("1.0Yi", iec(1_208_925_819_614_629_174_706_176_u128));
     }
+
+    #[test]
+    fn test_beyond_yotta_does_not_panic() {
+        // Values at or past the Yotta suffix must stay on the suffix table's last entry instead
+        // of indexing past it.
+        assert_eq!("1000.0Y", decimal(10u128.pow(27)));
+        assert_eq!("10000.0Y", decimal(10u128.pow(28)));
+
+        assert_eq!("1024.0Yi", iec(1_208_925_819_614_629_174_706_176_u128 * 1024));
+    }
+
+    #[test]
+    fn test_decimal_i128() {
+        assert_eq!("1.0", decimal_i128(1));
+        assert_eq!("-1.0", decimal_i128(-1));
+        assert_eq!("-1.0k", decimal_i128(-1000));
+        // i128::MIN has no positive counterpart, so this must not panic.
+        assert_eq!("-170141183460469.2Y", decimal_i128(i128::MIN));
+    }
+
+    #[test]
+    fn test_iec_i128() {
+        assert_eq!("1.0", iec_i128(1));
+        assert_eq!("-1.0ki", iec_i128(-1024));
+        // i128::MIN has no positive counterpart, so this must not panic.
+        assert_eq!("-140737488355328.0Yi", iec_i128(i128::MIN));
+    }
+
+    #[test]
+    fn test_decimal_with_truncate() {
+        // Matches the behavior of `decimal`/`iec` for the default precision.
+        let fmt = Format::default();
+        assert_eq!(decimal(2097151), decimal_with(2097151, fmt));
+        assert_eq!(iec(2097151), iec_with(2097151, fmt));
+    }
+
+    #[test]
+    fn test_decimal_with_precision() {
+        let fmt = Format {
+            decimals: 0,
+            rounding: Rounding::Truncate,
+        };
+        assert_eq!("1k", decimal_with(1999, fmt));
+
+        let fmt = Format {
+            decimals: 3,
+            rounding: Rounding::Truncate,
+        };
+        assert_eq!("1.999k", decimal_with(1999, fmt));
+    }
+
+    #[test]
+    fn test_decimal_with_half_up() {
+        let fmt = Format {
+            decimals: 0,
+            rounding: Rounding::HalfUp,
+        };
+        assert_eq!("2k", decimal_with(1999, fmt));
+        assert_eq!("2k", decimal_with(1500, fmt));
+        assert_eq!("1k", decimal_with(1499, fmt));
+    }
+
+    #[test]
+    fn test_decimal_with_half_even() {
+        let fmt = Format {
+            decimals: 0,
+            rounding: Rounding::HalfEven,
+        };
+        // Exact ties round to the nearest even integer part.
+        assert_eq!("2k", decimal_with(2500, fmt));
+        assert_eq!("4k", decimal_with(3500, fmt));
+    }
+
+    #[test]
+    fn test_decimal_with_carry() {
+        // Rounding the fraction up to 1.0 carries into the integer part.
+        let fmt = Format {
+            decimals: 1,
+            rounding: Rounding::HalfUp,
+        };
+        assert_eq!("2.0k", decimal_with(1950, fmt));
+    }
+
+    #[test]
+    fn test_decimal_with_decimals_overflow_clamped() {
+        // `decimals` is a public, unbounded `u8`; an unreasonably large value must be clamped
+        // rather than overflowing `10u128.pow(decimals)`/`remainder * 10^decimals`.
+        let fmt = Format {
+            decimals: 40,
+            rounding: Rounding::Truncate,
+        };
+        assert_eq!(
+            format!("1.{}k", "0".repeat(MAX_DECIMALS as usize)),
+            decimal_with(1000, fmt)
+        );
+    }
+
+    #[test]
+    fn test_decimal_scaled() {
+        assert_eq!("1.5k", decimal_scaled(1_500_000, 3));
+        assert_eq!(decimal(1234567), decimal_scaled(1234567, 0));
+        assert_eq!(decimal(1234567), decimal_scaled(1_234_567_000, 3));
+        assert_eq!("0.1", decimal_scaled(100, 3));
+    }
+
+    #[test]
+    fn test_iec_scaled() {
+        assert_eq!("1.5ki", iec_scaled(1536, 0));
+        assert_eq!(iec(10 * 1024 * 1024), iec_scaled(10 * 1024 * 1_024_000, 3));
+    }
+
+    #[test]
+    fn test_decimal_scaled_large_scale_no_overflow() {
+        // Largest possible Decimal128 mantissa at its largest legal scale must not panic.
+        assert_eq!("1.7", decimal_scaled(i128::MAX as u128, 38));
+    }
+
+    #[test]
+    fn test_decimal_scaled_scale_overflow_clamped() {
+        // `scale` is a public, unbounded `u32`; a value beyond any real fixed-point scale must be
+        // clamped rather than overflowing `10u128.pow(scale)`.
+        assert_eq!("0.0", decimal_scaled(100, 40));
+    }
+
+    #[test]
+    fn test_buffer_decimal() {
+        let mut buf = Buffer::new();
+        assert_eq!("1.0", buf.format_decimal(1));
+        assert_eq!("1.0k", buf.format_decimal(1000));
+        assert_eq!("10.0M", buf.format_decimal(10_000_000));
+        assert_eq!(
+            "1.0Y",
+            buf.format_decimal(1_000_000_000_000_000_000_000_000_u128)
+        );
+    }
+
+    #[test]
+    fn test_buffer_iec() {
+        let mut buf = Buffer::new();
+        assert_eq!("1.0", buf.format_iec(1));
+        assert_eq!("1.0ki", buf.format_iec(1024));
+        assert_eq!("1.9Mi", buf.format_iec(2097151));
+        assert_eq!(
+            "1.0Yi",
+            buf.format_iec(1_208_925_819_614_629_174_706_176_u128)
+        );
+    }
+
+    #[test]
+    fn test_buffer_matches_owned() {
+        let mut buf = Buffer::new();
+        for val in [0, 1, 999, 1000, 2097151, 10 * 1024 * 1024] {
+            assert_eq!(decimal(val), buf.format_decimal(val));
+            assert_eq!(iec(val), buf.format_iec(val));
+        }
+    }
+
+    #[test]
+    fn test_decimal_display() {
+        assert_eq!("1.0k", format!("{}", Decimal(1000)));
+        assert_eq!("1.500k", format!("{:.3}", Decimal(1500)));
+        assert_eq!("1k", format!("{:.0}", Decimal(1000)));
+        assert_eq!(" 1.0k", format!("{:>5}", Decimal(1000)));
+        assert_eq!("1.0k ", format!("{:<5}", Decimal(1000)));
+    }
+
+    #[test]
+    fn test_iec_display() {
+        assert_eq!("1.0ki", format!("{}", Iec(1024)));
+        assert_eq!("1.000ki", format!("{:.3}", Iec(1024)));
+        assert_eq!("  1.0ki", format!("{:>7}", Iec(1024)));
+    }
+
+    #[test]
+    fn test_decimal_iec_display_precision_overflow_clamped() {
+        // `f.precision()` is an unbounded `usize`; requesting more fractional digits than
+        // `MAX_DECIMALS` must clamp rather than overflowing `reduce_magnitude`'s arithmetic.
+        assert_eq!(
+            format!("0.{}", "0".repeat(MAX_DECIMALS as usize)),
+            format!("{:.39}", Decimal(0))
+        );
+        assert_eq!(
+            format!("0.{}", "0".repeat(MAX_DECIMALS as usize)),
+            format!("{:.39}", Iec(0))
+        );
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(Ok(1), parse_decimal("1.0"));
+        assert_eq!(Ok(1000), parse_decimal("1.0k"));
+        assert_eq!(Ok(1500), parse_decimal("1.5k"));
+        assert_eq!(Ok(10_000_000), parse_decimal("10M"));
+        assert_eq!(Ok(1000), parse_decimal(" 1000 "));
+        assert_eq!(Err(ParseError::InvalidNumber), parse_decimal(""));
+    }
+
+    #[test]
+    fn test_parse_iec() {
+        assert_eq!(Ok(1024), parse_iec("1.0ki"));
+        assert_eq!(Ok(10 * 1024 * 1024), parse_iec("10Mi"));
+        assert_eq!(Ok(1_208_925_819_614_629_174_706_176_u128), parse_iec("1.0Yi"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Err(ParseError::UnknownSuffix), parse_decimal("1.0x"));
+        assert_eq!(Err(ParseError::InvalidNumber), parse_decimal("k"));
+        assert_eq!(Err(ParseError::InvalidNumber), parse_decimal("."));
+    }
+
+    #[test]
+    fn test_parse_digits_too_big_is_overflow() {
+        // Syntactically valid (all-digit) but too large for a `u128` must be `Overflow`, not
+        // `InvalidNumber` — callers are meant to branch on the two differently.
+        assert_eq!(
+            Err(ParseError::Overflow),
+            parse_decimal("999999999999999999999999999999999999999999999999")
+        );
+        assert_eq!(
+            Err(ParseError::Overflow),
+            parse_decimal("1.999999999999999999999999999999999999999999999999")
+        );
+        // A genuinely malformed number (a second `.`) is still `InvalidNumber`.
+        assert_eq!(Err(ParseError::InvalidNumber), parse_decimal("1.2.3"));
+    }
 }